@@ -80,6 +80,11 @@ async fn main() -> std::io::Result<()> {
         .await
         .failed("Invalid configuration");
 
+    // Parse shutdown timeout
+    let shutdown_timeout = config
+        .property_or_static::<Duration>("server.shutdown-timeout", "1s")
+        .failed("Invalid configuration");
+
     // Init servers
     let (delivery_tx, delivery_rx) = mpsc::channel(IPC_CHANNEL_BUFFER);
     let smtp = SMTP::init(&config, &servers, &stores, &directory, delivery_tx)
@@ -125,6 +130,7 @@ async fn main() -> std::io::Result<()> {
     for scheduler in schedulers {
         scheduler.spawn(shutdown_rx.clone());
     }
+    drop(shutdown_rx);
 
     // Wait for shutdown signal
     wait_for_shutdown(&format!(
@@ -137,7 +143,7 @@ async fn main() -> std::io::Result<()> {
     let _ = shutdown_tx.send(true);
 
     // Wait for services to finish
-    tokio::time::sleep(Duration::from_secs(1)).await;
+    let _ = tokio::time::timeout(shutdown_timeout, shutdown_tx.closed()).await;
 
     Ok(())
 }